@@ -1,20 +1,26 @@
+// The codebase spells out `field: field` in struct initializers throughout; keep
+// that house style rather than letting clippy rewrite it to the shorthand.
+#![allow(clippy::redundant_field_names)]
+
 use chrono::prelude::*;
 use chrono::Days;
 use chrono::Utc;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::collections::HashSet;
 use uuid::Uuid;
 
-#[derive(Clone, Hash, PartialEq, Eq)]
+#[derive(Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct EngineerIdentifier {
     pub value: Uuid,
 }
 
-#[derive(Clone, Hash, PartialEq, Eq)]
+#[derive(Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Engineer {
     name: String,
     identifier: EngineerIdentifier,
     last_time_served: AppDate,
-    today_strategy: TodayStrategy,
 }
 
 impl Engineer {
@@ -23,7 +29,6 @@ impl Engineer {
             name: name.to_string(),
             identifier: identifier,
             last_time_served: last_time_served,
-            today_strategy: TodayStrategy::OSDate,
         }
     }
 
@@ -31,56 +36,103 @@ impl Engineer {
         self.identifier.clone()
     }
 
-    pub fn support_days_for_month(self, month: Month) -> Vec<AppDate> {
-        todo!()
-    }
-
-    pub fn serve_support(self) -> Result<Self, DomainError> {
-        todo!()
+    pub fn serve_support(self, clock: &dyn Clock) -> Result<Self, DomainError> {
+        Ok(Engineer {
+            last_time_served: AppDate::new(clock.today()),
+            ..self
+        })
     }
 
     pub fn last_time_served(&self) -> AppDate {
         self.last_time_served.clone()
     }
-}
 
-pub enum Month {}
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
 
-#[derive(Clone, Hash, PartialEq, Eq)]
-enum TodayStrategy {
-    OSDate,
-    Thursday,
-    Friday,
-    Weekend,
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Month {
+    Jan,
+    Feb,
+    Mar,
+    Apr,
+    May,
+    Jun,
+    Jul,
+    Aug,
+    Sep,
+    Oct,
+    Nov,
+    Dec,
 }
 
-impl TodayStrategy {
-    pub fn execute(&self) -> AppDate {
+impl Month {
+    pub fn number(&self) -> u32 {
         match self {
-            Self::OSDate => AppDate::new(Utc::now().date_naive()),
-            Self::Thursday => AppDate::new(
-                Utc.with_ymd_and_hms(2022, 12, 15, 10, 0, 0)
-                    .unwrap()
-                    .date_naive(),
-            ),
-            Self::Friday => AppDate::new(
-                Utc.with_ymd_and_hms(2022, 12, 16, 10, 0, 0)
-                    .unwrap()
-                    .date_naive(),
-            ),
-            Self::Weekend => AppDate::new(
-                Utc.with_ymd_and_hms(2022, 12, 18, 10, 0, 0)
-                    .unwrap()
-                    .date_naive(),
-            ),
+            Self::Jan => 1,
+            Self::Feb => 2,
+            Self::Mar => 3,
+            Self::Apr => 4,
+            Self::May => 5,
+            Self::Jun => 6,
+            Self::Jul => 7,
+            Self::Aug => 8,
+            Self::Sep => 9,
+            Self::Oct => 10,
+            Self::Nov => 11,
+            Self::Dec => 12,
         }
     }
 }
 
+pub trait Clock {
+    fn today(&self) -> NaiveDate;
+}
+
+pub struct SystemClock {
+    offset: FixedOffset,
+}
+
+impl SystemClock {
+    pub fn new(offset: FixedOffset) -> Self {
+        Self { offset: offset }
+    }
+
+    pub fn utc() -> Self {
+        Self::new(FixedOffset::east_opt(0).unwrap())
+    }
+}
+
+impl Clock for SystemClock {
+    fn today(&self) -> NaiveDate {
+        Utc::now().with_timezone(&self.offset).date_naive()
+    }
+}
+
+pub struct FixedClock {
+    today: NaiveDate,
+}
+
+impl FixedClock {
+    pub fn new(today: NaiveDate) -> Self {
+        Self { today: today }
+    }
+}
+
+impl Clock for FixedClock {
+    fn today(&self) -> NaiveDate {
+        self.today
+    }
+}
+
+#[derive(Serialize, Deserialize)]
 pub struct EngineeringDepartment {
     engineer_serving_support_today: Option<Engineer>,
     engineers_by_last_date_served: HashMap<AppDate, Engineer>,
     reservations_for_month: HashMap<AppDate, Engineer>,
+    holiday_calendar: HolidayCalendar,
     rota: Rota
 }
 
@@ -89,58 +141,171 @@ impl EngineeringDepartment {
         engineers: Vec<Engineer>,
         engineer_serving_support_today: Option<Engineer>,
         reservations_for_month: HashMap<AppDate, Engineer>,
-    ) -> Self {
-        Self {
-            engineers_by_last_date_served: Self::engineers_by_last_date_served(&engineers),
+        holiday_calendar: HolidayCalendar,
+        clock: &dyn Clock,
+    ) -> Result<Self, DomainError> {
+        Ok(Self {
+            engineers_by_last_date_served: Self::engineers_by_last_date_served(&engineers)?,
             engineer_serving_support_today: engineer_serving_support_today,
             reservations_for_month: reservations_for_month,
-            rota: Self::rota(&engineers),
-        }
+            rota: Self::rota(&engineers, &holiday_calendar, clock),
+            holiday_calendar: holiday_calendar,
+        })
     }
 
     pub fn mark_support_service_for_engineer(
-        _eng: Engineer,
+        mut self,
+        engineer: Engineer,
+        clock: &dyn Clock,
     ) -> Result<EngineeringDepartment, DomainError> {
-        todo!()
+        let today = AppDate::new(clock.today());
+        let served = engineer.serve_support(clock)?;
+        self.insert_engineer_by_last_date_served(served.clone())?;
+        self.reservations_for_month.insert(today, served.clone());
+        self.engineer_serving_support_today = Some(served);
+        Ok(self)
     }
 
-    pub fn engineer_serving_on_date(self, date: AppDate) -> Result<Engineer, DomainError> {
-        match self.reservations_for_month.get(&date) {
-            Some(engineer) => Ok(engineer.clone()),
-            None => self.compute_engineer_serving_on_date(date),
+    pub fn reserve(
+        mut self,
+        date: AppDate,
+        engineer: Engineer,
+    ) -> Result<EngineeringDepartment, DomainError> {
+        if !date.is_business_day(&self.holiday_calendar) {
+            return Err(DomainError::not_a_business_day());
+        }
+        if let Some(existing) = self.reservations_for_month.get(&date) {
+            if existing.identifier() != engineer.identifier() {
+                return Err(DomainError::date_already_reserved());
+            }
         }
+        self.reservations_for_month.insert(date, engineer);
+        Ok(self)
     }
 
-    fn compute_engineer_serving_on_date(self, date: AppDate) -> Result<Engineer, DomainError> {
-        let last_date_served_by_engineer =
-            date.last_date_served_by_engineer(self.rota)?;
+    fn insert_engineer_by_last_date_served(
+        &mut self,
+        engineer: Engineer,
+    ) -> Result<(), DomainError> {
+        let key = engineer.last_time_served();
+        if let Some(existing) = self.engineers_by_last_date_served.get(&key) {
+            if existing.identifier() != engineer.identifier() {
+                return Err(DomainError::duplicate_last_date_served());
+            }
+        }
         self.engineers_by_last_date_served
-            .get(&last_date_served_by_engineer)
-            .map_or_else(|| Err(DomainError::no_engineer_found()), |e| Ok(e.clone()))
+            .retain(|_, e| e.identifier() != engineer.identifier());
+        self.engineers_by_last_date_served.insert(key, engineer);
+        Ok(())
+    }
+
+    pub fn engineer_serving_on_date(
+        &self,
+        date: AppDate,
+        clock: &dyn Clock,
+    ) -> Result<Engineer, DomainError> {
+        match self.reservations_for_month.get(&date) {
+            Some(engineer) => Ok(engineer.clone()),
+            None => self.compute_engineer_serving_on_date(date, clock),
+        }
     }
 
-    pub fn calendar(period: Period) -> Calendar {
-        todo!()
+    fn compute_engineer_serving_on_date(
+        &self,
+        date: AppDate,
+        clock: &dyn Clock,
+    ) -> Result<Engineer, DomainError> {
+        let anchor = self
+            .engineer_serving_support_today
+            .as_ref()
+            .ok_or_else(DomainError::no_engineer_found)?;
+        let rota = self.engineers_in_rota_order();
+        let anchor_position = rota
+            .iter()
+            .position(|engineer| engineer.identifier() == anchor.identifier())
+            .ok_or_else(DomainError::no_engineer_found)?;
+        let offset =
+            date.cycle_offset_from_today(self.rota.length_in_days(), &self.holiday_calendar, clock)?;
+        let position_in_cycle = (anchor_position + offset) % rota.len();
+        Ok(rota[position_in_cycle].clone())
     }
 
-    fn engineers_by_last_date_served(engineers: &Vec<Engineer>) -> HashMap<AppDate, Engineer> {
+    fn engineers_in_rota_order(&self) -> Vec<Engineer> {
+        let mut engineers = self
+            .engineers_by_last_date_served
+            .values()
+            .cloned()
+            .collect::<Vec<Engineer>>();
+        engineers.sort_by_key(|engineer| engineer.last_time_served().value);
         engineers
-            .to_vec()
-            .into_iter()
-            .map(|e| (e.clone().last_time_served(), e))
-            .collect::<HashMap<AppDate, Engineer>>()
     }
 
-    fn rota(engineers: &Vec<Engineer>) -> Rota {
+    pub fn calendar(&self, period: Period, clock: &dyn Clock) -> Calendar {
+        let month = period.month.number();
+        let year = period.year.value() as i32;
+        let cells = (1..=AppDate::days_in_month(year, month))
+            .filter_map(|day| NaiveDate::from_ymd_opt(year, month, day))
+            .map(|date| {
+                let app_date = AppDate::new(date);
+                let engineer = if app_date.is_business_day(&self.holiday_calendar) {
+                    self.engineer_serving_on_date(app_date, clock)
+                        .ok()
+                        .map(|e| e.name().to_string())
+                } else {
+                    None
+                };
+                CalendarCell::new(date, engineer)
+            })
+            .collect();
+        Calendar::new(year, month, cells)
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), DomainError> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| DomainError {
+            message: e.to_string(),
+        })?;
+        std::fs::write(path, json).map_err(|e| DomainError {
+            message: e.to_string(),
+        })
+    }
+
+    pub fn load(path: &str) -> Result<Self, DomainError> {
+        let json = std::fs::read_to_string(path).map_err(|e| DomainError {
+            message: e.to_string(),
+        })?;
+        serde_json::from_str(&json).map_err(|e| DomainError {
+            message: e.to_string(),
+        })
+    }
+
+    fn engineers_by_last_date_served(
+        engineers: &[Engineer],
+    ) -> Result<HashMap<AppDate, Engineer>, DomainError> {
+        let mut engineers_by_last_date_served: HashMap<AppDate, Engineer> = HashMap::new();
+        for engineer in engineers {
+            let key = engineer.last_time_served();
+            if let Some(existing) = engineers_by_last_date_served.get(&key) {
+                if existing.identifier() != engineer.identifier() {
+                    return Err(DomainError::duplicate_last_date_served());
+                }
+            }
+            engineers_by_last_date_served.insert(key, engineer.clone());
+        }
+        Ok(engineers_by_last_date_served)
+    }
+
+    fn rota(engineers: &[Engineer], holiday_calendar: &HolidayCalendar, clock: &dyn Clock) -> Rota {
         let number_of_engineers = engineers.len() as i64;
         let number_of_business_days_in_a_week = 5;
         let number_of_days_in_weekend = 2;
         let lenght_in_days = number_of_engineers / number_of_business_days_in_a_week * number_of_days_in_weekend
             + number_of_engineers;
-        Rota::new(lenght_in_days)
+        let holidays_in_cycle = holiday_calendar.holidays_in_range(clock.today(), lenght_in_days);
+        Rota::new(lenght_in_days + holidays_in_cycle)
     }
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct Rota {
     length_in_days: i64,
 }
@@ -156,13 +321,144 @@ impl Rota {
     }
 }
 
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct HolidayCalendar {
+    fixed: HashSet<NaiveDate>,
+    recurring: HashSet<(u32, u32)>,
+}
+
+impl HolidayCalendar {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_fixed(mut self, date: NaiveDate) -> Self {
+        self.fixed.insert(date);
+        self
+    }
+
+    pub fn with_recurring(mut self, month: u32, day: u32) -> Self {
+        self.recurring.insert((month, day));
+        self
+    }
+
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.fixed.contains(&date) || self.recurring.contains(&(date.month(), date.day()))
+    }
+
+    fn holidays_in_range(&self, start: NaiveDate, length_in_days: i64) -> i64 {
+        (0..length_in_days)
+            .filter_map(|offset| start.checked_add_days(Days::new(offset as u64)))
+            .filter(|date| {
+                date.weekday() != Weekday::Sat
+                    && date.weekday() != Weekday::Sun
+                    && self.is_holiday(*date)
+            })
+            .count() as i64
+    }
+}
+
+pub struct CalendarCell {
+    date: NaiveDate,
+    engineer: Option<String>,
+}
+
+impl CalendarCell {
+    pub fn new(date: NaiveDate, engineer: Option<String>) -> Self {
+        Self {
+            date: date,
+            engineer: engineer,
+        }
+    }
+}
+
 pub struct Calendar {
-    dates: Vec<AppDate>,
+    year: i32,
+    month: u32,
+    cells: Vec<CalendarCell>,
 }
 
 impl Calendar {
-    pub fn new(dates: Vec<AppDate>) -> Self {
-        Self { dates: dates }
+    pub fn new(year: i32, month: u32, cells: Vec<CalendarCell>) -> Self {
+        Self {
+            year: year,
+            month: month,
+            cells: cells,
+        }
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("## {}-{:02}\n\n", self.year, self.month);
+        out.push_str("| Mon | Tue | Wed | Thu | Fri |\n");
+        out.push_str("| --- | --- | --- | --- | --- |\n");
+        for week in self.weeks() {
+            let row = week
+                .iter()
+                .map(|cell| match cell {
+                    Some(cell) => match &cell.engineer {
+                        Some(name) => format!("{} {}", cell.date.day(), name),
+                        None => format!("~~{}~~", cell.date.day()),
+                    },
+                    None => String::new(),
+                })
+                .collect::<Vec<String>>()
+                .join(" | ");
+            out.push_str(&format!("| {} |\n", row));
+        }
+        out
+    }
+
+    pub fn to_html(&self) -> String {
+        let mut out = format!("<table>\n<caption>{}-{:02}</caption>\n", self.year, self.month);
+        out.push_str("<tr><th>Mon</th><th>Tue</th><th>Wed</th><th>Thu</th><th>Fri</th></tr>\n");
+        for week in self.weeks() {
+            out.push_str("<tr>");
+            for cell in week {
+                match cell {
+                    Some(cell) => match &cell.engineer {
+                        Some(name) => out.push_str(&format!(
+                            "<td>{} {}</td>",
+                            cell.date.day(),
+                            name
+                        )),
+                        None => out.push_str(&format!(
+                            "<td class=\"grey\">{}</td>",
+                            cell.date.day()
+                        )),
+                    },
+                    None => out.push_str("<td></td>"),
+                }
+            }
+            out.push_str("</tr>\n");
+        }
+        out.push_str("</table>\n");
+        out
+    }
+
+    fn weeks(&self) -> Vec<Vec<Option<&CalendarCell>>> {
+        let mut weeks = Vec::new();
+        let mut week: Vec<Option<&CalendarCell>> = Vec::new();
+        for cell in &self.cells {
+            let weekday = cell.date.weekday();
+            if weekday == Weekday::Sat || weekday == Weekday::Sun {
+                continue;
+            }
+            if weekday == Weekday::Mon && !week.is_empty() {
+                while week.len() < 5 {
+                    week.push(None);
+                }
+                weeks.push(std::mem::take(&mut week));
+            }
+            while week.len() < weekday.num_days_from_monday() as usize {
+                week.push(None);
+            }
+            week.push(Some(cell));
+        }
+        while week.len() < 5 {
+            week.push(None);
+        }
+        weeks.push(week);
+        weeks
     }
 }
 
@@ -180,6 +476,10 @@ impl Period {
             year: year,
         }
     }
+
+    pub fn engineer_identifier(&self) -> EngineerIdentifier {
+        self.engineer_identifier.clone()
+    }
 }
 
 pub struct Year {
@@ -190,9 +490,14 @@ impl Year {
     pub fn new(value: u16) -> Self {
         Self { value: value }
     }
+
+    pub fn value(&self) -> u16 {
+        self.value
+    }
 }
 
-#[derive(Clone, Hash, PartialEq, Eq)]
+#[derive(Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct AppDate {
     value: NaiveDate,
 }
@@ -202,54 +507,112 @@ impl AppDate {
         Self { value: value }
     }
 
-    pub fn is_business_day(&self) -> bool {
-        self.value.weekday() != Weekday::Sat && self.value.weekday() != Weekday::Sun
+    pub fn parse(input: &str, today: AppDate) -> Result<AppDate, DomainError> {
+        let trimmed = input.trim();
+        match trimmed.to_lowercase().as_str() {
+            "today" => Ok(today),
+            "tomorrow" => today
+                .value
+                .checked_add_days(Days::new(1))
+                .map(AppDate::new)
+                .ok_or_else(DomainError::date_is_out_of_range),
+            lowered => match Self::weekday_from_name(lowered) {
+                Some(weekday) => Ok(today.next_occurrence_of(weekday)),
+                None => Self::parse_underscore_date(trimmed),
+            },
+        }
+    }
+
+    fn weekday_from_name(name: &str) -> Option<Weekday> {
+        match name {
+            "monday" => Some(Weekday::Mon),
+            "tuesday" => Some(Weekday::Tue),
+            "wednesday" => Some(Weekday::Wed),
+            "thursday" => Some(Weekday::Thu),
+            "friday" => Some(Weekday::Fri),
+            "saturday" => Some(Weekday::Sat),
+            "sunday" => Some(Weekday::Sun),
+            _ => None,
+        }
     }
 
-    pub fn last_date_served_by_engineer(
-        self,
-        rota: Rota,
-    ) -> Result<AppDate, DomainError> {
-        let number_of_days_to_go_back = self.number_of_days_to_go_back(rota.length_in_days())?;
-        self.go_back_to_nearest_business_day(number_of_days_to_go_back)
+    fn next_occurrence_of(&self, weekday: Weekday) -> AppDate {
+        let current = self.value.weekday().num_days_from_monday() as i64;
+        let target = weekday.num_days_from_monday() as i64;
+        let mut days_ahead = (target - current).rem_euclid(7);
+        if days_ahead == 0 {
+            days_ahead = 7;
+        }
+        AppDate::new(self.value + Days::new(days_ahead as u64))
     }
 
-    fn number_of_days_to_go_back(&self, rota_length_in_days: i64) -> Result<i64, DomainError> {
-        let number_of_days_from_today = self.number_of_days_from_today()?;
-        let number_of_days_to_complete_rota = number_of_days_from_today % rota_length_in_days;
-        Ok(rota_length_in_days - number_of_days_to_complete_rota)
+    fn parse_underscore_date(input: &str) -> Result<AppDate, DomainError> {
+        let (month_token, rest) = input.split_once('_').ok_or_else(|| DomainError {
+            message: format!("could not parse date from '{}'", input),
+        })?;
+        let capitalized_month = Self::capitalize(month_token);
+        let normalized = format!("{}_{}", capitalized_month, rest);
+        NaiveDate::parse_from_str(&normalized, "%b_%d_%Y")
+            .map(AppDate::new)
+            .map_err(|_| DomainError {
+                message: format!("could not parse date from '{}'", input),
+            })
     }
 
-    fn go_back_to_nearest_business_day(
-        self,
-        number_of_days_to_go_back: i64,
-    ) -> Result<AppDate, DomainError> {
-        let n_days_ago = self.go_back_n_days(number_of_days_to_go_back)?;
-        if n_days_ago.is_business_day() {
-            Ok(n_days_ago)
-        } else {
-            n_days_ago.go_back_n_days(2)
+    fn capitalize(token: &str) -> String {
+        let mut chars = token.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().collect::<String>() + &chars.as_str().to_lowercase(),
+            None => String::new(),
         }
     }
 
-    fn go_back_n_days(self, number_of_days_to_go_back: i64) -> Result<AppDate, DomainError> {
-        self.value
-            .checked_sub_days(Days::new(number_of_days_to_go_back as u64))
-            .map_or_else(
-                || Err(DomainError::date_is_out_of_range()),
-                |date| Ok(AppDate::new(date)),
-            )
+    fn days_in_month(year: i32, month: u32) -> u32 {
+        let (next_year, next_month) = if month == 12 {
+            (year + 1, 1)
+        } else {
+            (year, month + 1)
+        };
+        NaiveDate::from_ymd_opt(next_year, next_month, 1)
+            .and_then(|first_of_next| first_of_next.pred_opt())
+            .map(|last| last.day())
+            .unwrap_or(28)
     }
 
-    fn number_of_days_from_today(&self) -> Result<i64, DomainError> {
-        let days_delta = (self.value - chrono::Utc::now().date_naive()).num_days();
-        if days_delta > 0 {
-            Ok(days_delta)
-        } else {
-            Err(DomainError {
-                message: String::from("Can't tell you who served in the past!"),
-            })
+    pub fn is_business_day(&self, holiday_calendar: &HolidayCalendar) -> bool {
+        self.value.weekday() != Weekday::Sat
+            && self.value.weekday() != Weekday::Sun
+            && !holiday_calendar.is_holiday(self.value)
+    }
+
+    /// Offset, in rota turns, of the engineer serving on `self` from the anchor
+    /// engineer serving today. The anchor is offset `0`; every business day that
+    /// elapses advances the rota by one, while weekends and holidays resolve to
+    /// the preceding business day's engineer. The signed day delta is folded into
+    /// a single cycle with `rem_euclid`, so past and future dates are symmetric.
+    fn cycle_offset_from_today(
+        &self,
+        rota_length_in_days: i64,
+        holiday_calendar: &HolidayCalendar,
+        clock: &dyn Clock,
+    ) -> Result<usize, DomainError> {
+        let position_in_cycle =
+            self.number_of_days_from_today(clock).rem_euclid(rota_length_in_days);
+        let today = clock.today();
+        let mut offset = 0usize;
+        for days_ahead in 1..=position_in_cycle {
+            let date = today
+                .checked_add_days(Days::new(days_ahead as u64))
+                .ok_or_else(DomainError::date_is_out_of_range)?;
+            if AppDate::new(date).is_business_day(holiday_calendar) {
+                offset += 1;
+            }
         }
+        Ok(offset)
+    }
+
+    fn number_of_days_from_today(&self, clock: &dyn Clock) -> i64 {
+        (self.value - clock.today()).num_days()
     }
 }
 
@@ -270,7 +633,188 @@ impl DomainError {
             message: String::from("no engineer found"),
         }
     }
+
+    pub fn not_a_business_day() -> Self {
+        Self {
+            message: String::from("cannot reserve a non-business day"),
+        }
+    }
+
+    pub fn date_already_reserved() -> Self {
+        Self {
+            message: String::from("date is already reserved by another engineer"),
+        }
+    }
+
+    pub fn duplicate_last_date_served() -> Self {
+        Self {
+            message: String::from("another engineer already served on that date"),
+        }
+    }
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use uuid::Uuid;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn engineer(name: &str, identifier: u128, last_time_served: NaiveDate) -> Engineer {
+        Engineer::new(
+            name,
+            EngineerIdentifier {
+                value: Uuid::from_u128(identifier),
+            },
+            AppDate::new(last_time_served),
+        )
+    }
+
+    // Five engineers on a rota of length 7 (five business days plus the weekend),
+    // anchored so that `e0` serves on the fixed "today" of Thu 2022-12-01. Each
+    // engineer's seed `last_time_served` is the business day it served one cycle
+    // earlier, in rota order.
+    fn department() -> (EngineeringDepartment, Vec<Engineer>) {
+        let engineers = vec![
+            engineer("e0", 0, date(2022, 11, 24)),
+            engineer("e1", 1, date(2022, 11, 25)),
+            engineer("e2", 2, date(2022, 11, 28)),
+            engineer("e3", 3, date(2022, 11, 29)),
+            engineer("e4", 4, date(2022, 11, 30)),
+        ];
+        let department = EngineeringDepartment::new(
+            engineers.clone(),
+            Some(engineers[0].clone()),
+            HashMap::new(),
+            HolidayCalendar::new(),
+            &FixedClock::new(date(2022, 12, 1)),
+        )
+        .unwrap();
+        (department, engineers)
+    }
+
+    fn serving_name(department: &EngineeringDepartment, day: NaiveDate) -> String {
+        department
+            .engineer_serving_on_date(AppDate::new(day), &FixedClock::new(date(2022, 12, 1)))
+            .unwrap()
+            .name()
+            .to_string()
+    }
+
+    #[test]
+    fn serves_today_and_future_business_days_in_rota_order() {
+        let (department, _) = department();
+        assert_eq!(serving_name(&department, date(2022, 12, 1)), "e0");
+        assert_eq!(serving_name(&department, date(2022, 12, 2)), "e1");
+        assert_eq!(serving_name(&department, date(2022, 12, 5)), "e2");
+        assert_eq!(serving_name(&department, date(2022, 12, 6)), "e3");
+        assert_eq!(serving_name(&department, date(2022, 12, 7)), "e4");
+        assert_eq!(serving_name(&department, date(2022, 12, 8)), "e0");
+    }
+
+    #[test]
+    fn resolves_past_dates() {
+        let (department, _) = department();
+        assert_eq!(serving_name(&department, date(2022, 11, 29)), "e3");
+    }
+
+    #[test]
+    fn weekend_resolves_to_the_preceding_business_day() {
+        let (department, _) = department();
+        assert_eq!(serving_name(&department, date(2022, 12, 3)), "e1");
+    }
+
+    #[test]
+    fn holiday_resolves_to_the_preceding_business_day() {
+        let engineers = vec![
+            engineer("e0", 0, date(2022, 11, 24)),
+            engineer("e1", 1, date(2022, 11, 25)),
+        ];
+        let calendar = HolidayCalendar::new().with_fixed(date(2022, 12, 2));
+        let clock = FixedClock::new(date(2022, 12, 1));
+        let department = EngineeringDepartment::new(
+            engineers.clone(),
+            Some(engineers[0].clone()),
+            HashMap::new(),
+            calendar,
+            &clock,
+        )
+        .unwrap();
+        let served = department
+            .engineer_serving_on_date(AppDate::new(date(2022, 12, 2)), &clock)
+            .unwrap();
+        assert_eq!(served.name(), "e0");
+    }
+
+    #[test]
+    fn reserve_rejects_a_non_business_day() {
+        let (department, engineers) = department();
+        let result = department.reserve(AppDate::new(date(2022, 12, 3)), engineers[0].clone());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn reserve_rejects_a_date_already_taken_by_another_engineer() {
+        let (department, engineers) = department();
+        let department = department
+            .reserve(AppDate::new(date(2022, 12, 9)), engineers[0].clone())
+            .unwrap();
+        let result = department.reserve(AppDate::new(date(2022, 12, 9)), engineers[1].clone());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn constructor_rejects_engineers_sharing_a_last_served_date() {
+        let engineers = vec![
+            engineer("e0", 0, date(2022, 11, 24)),
+            engineer("e1", 1, date(2022, 11, 24)),
+        ];
+        let result = EngineeringDepartment::new(
+            engineers,
+            None,
+            HashMap::new(),
+            HolidayCalendar::new(),
+            &FixedClock::new(date(2022, 12, 1)),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn marking_service_rejects_a_colliding_last_served_date() {
+        let (department, engineers) = department();
+        let clock = FixedClock::new(date(2022, 12, 1));
+        let department = department
+            .mark_support_service_for_engineer(engineers[1].clone(), &clock)
+            .unwrap();
+        let result = department.mark_support_service_for_engineer(engineers[2].clone(), &clock);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_reads_relative_and_explicit_dates() {
+        let today = AppDate::new(date(2022, 12, 1));
+        assert!(AppDate::parse("today", today.clone()).unwrap() == today);
+        assert!(
+            AppDate::parse("tomorrow", today.clone()).unwrap() == AppDate::new(date(2022, 12, 2))
+        );
+        assert!(
+            AppDate::parse("friday", today.clone()).unwrap() == AppDate::new(date(2022, 12, 2))
+        );
+        assert!(
+            AppDate::parse("thursday", today.clone()).unwrap() == AppDate::new(date(2022, 12, 8))
+        );
+        assert!(AppDate::parse("dec_15_2022", today).unwrap() == AppDate::new(date(2022, 12, 15)));
+        assert!(AppDate::parse("not a date", AppDate::new(date(2022, 12, 1))).is_err());
+    }
+
+    #[test]
+    fn department_state_round_trips_through_serde() {
+        let (department, _) = department();
+        let json = serde_json::to_string(&department).unwrap();
+        let restored: EngineeringDepartment = serde_json::from_str(&json).unwrap();
+        assert_eq!(serving_name(&restored, date(2022, 12, 6)), "e3");
+    }
+}